@@ -1,3 +1,4 @@
+use ggez::audio::{self, SoundSource};
 use ggez::event::{self, EventHandler};
 use ggez::graphics::{self, Canvas, Color, DrawParam, Mesh, Text, TextFragment};
 use ggez::input::mouse::MouseButton;
@@ -10,8 +11,11 @@ const WINDOW_WIDTH: f32 = 800.0;
 const WINDOW_HEIGHT: f32 = 600.0;
 const PADDLE_WIDTH: f32 = 20.0;
 const PADDLE_HEIGHT: f32 = 100.0;
-const PADDLE_SPEED: f32 = 5.0;
+const PADDLE_SPEED: f32 = 300.0;
 const BALL_RADIUS: f32 = 10.0;
+const SCORE_TARGET: u32 = 11;
+const MAX_BOUNCE_ANGLE: f32 = std::f32::consts::PI / 3.0;
+const RALLY_SPEEDUP: f32 = 1.05;
 
 #[derive(PartialEq)]
 enum Difficulty {
@@ -26,6 +30,7 @@ enum GameState {
     Menu,
     Playing,
     Paused,
+    GameOver,
 }
 
 struct MainState {
@@ -34,38 +39,154 @@ struct MainState {
     ball_position: Vec2,
     ball_velocity: Vec2,
     difficulty: Difficulty,
+    base_speed: f32,
+    max_speed: f32,
+    single_player: bool,
     game_state: GameState,
+    left_score: u32,
+    right_score: u32,
     menu_selection: usize,
     pause_selection: usize,
+    gameover_selection: usize,
     left_paddle_dragging: bool,
     right_paddle_dragging: bool,
+    left_up_held: bool,
+    left_down_held: bool,
+    right_up_held: bool,
+    right_down_held: bool,
+    paddle_sound: audio::Source,
+    wall_sound: audio::Source,
+    score_sound: audio::Source,
+    volume: f32,
 }
 
 impl MainState {
-    fn new() -> Self {
-        MainState {
+    fn new(ctx: &mut Context) -> GameResult<Self> {
+        let mut state = MainState {
             left_paddle_y: WINDOW_HEIGHT / 2.0 - PADDLE_HEIGHT / 2.0,
             right_paddle_y: WINDOW_HEIGHT / 2.0 - PADDLE_HEIGHT / 2.0,
             ball_position: Vec2::new(WINDOW_WIDTH / 2.0, WINDOW_HEIGHT / 2.0),
             ball_velocity: Vec2::new(0.0, 0.0),
             difficulty: Difficulty::Medium,
+            base_speed: 240.0,
+            max_speed: 420.0,
+            single_player: false,
             game_state: GameState::Home,
-            menu_selection: 1,
+            left_score: 0,
+            right_score: 0,
+            menu_selection: 2,
             pause_selection: 0,
+            gameover_selection: 0,
             left_paddle_dragging: false,
             right_paddle_dragging: false,
+            left_up_held: false,
+            left_down_held: false,
+            right_up_held: false,
+            right_down_held: false,
+            paddle_sound: audio::Source::new(ctx, "/paddle.wav")?,
+            wall_sound: audio::Source::new(ctx, "/wall.wav")?,
+            score_sound: audio::Source::new(ctx, "/score.wav")?,
+            volume: 0.5,
+        };
+        let volume = state.volume;
+        state.set_volume(volume);
+        Ok(state)
+    }
+
+    // Apply a single master volume (clamped to [0, 1]) to every sound source.
+    fn set_volume(&mut self, volume: f32) {
+        self.volume = volume.clamp(0.0, 1.0);
+        self.paddle_sound.set_volume(self.volume);
+        self.wall_sound.set_volume(self.volume);
+        self.score_sound.set_volume(self.volume);
+    }
+
+    // Base (serve) and maximum (rally ceiling) ball speeds for each difficulty.
+    fn difficulty_speeds(&self) -> (f32, f32) {
+        match self.difficulty {
+            Difficulty::Easy => (120.0, 240.0),
+            Difficulty::Medium => (240.0, 420.0),
+            Difficulty::Hard => (360.0, 600.0),
         }
     }
 
     fn start_game(&mut self) {
-        self.ball_velocity = match self.difficulty {
-            Difficulty::Easy => Vec2::new(2.0, 2.0),
-            Difficulty::Medium => Vec2::new(4.0, 4.0),
-            Difficulty::Hard => Vec2::new(6.0, 6.0),
-        };
+        self.left_score = 0;
+        self.right_score = 0;
+        let (base, max) = self.difficulty_speeds();
+        self.base_speed = base;
+        self.max_speed = max;
+        self.serve_ball(false);
         self.game_state = GameState::Playing;
     }
 
+    // Recentre the ball and fire it toward the player who just conceded
+    // (leftwards when `toward_left`, rightwards otherwise), resetting the rally
+    // back to the difficulty's base speed.
+    fn serve_ball(&mut self, toward_left: bool) {
+        self.ball_position = Vec2::new(WINDOW_WIDTH / 2.0, WINDOW_HEIGHT / 2.0);
+        let dir = if toward_left { -1.0 } else { 1.0 };
+        self.ball_velocity = Vec2::new(dir * self.base_speed, self.base_speed);
+    }
+
+    // Speed the ball up a notch after each successful return, preserving the
+    // deflection direction and clamping the magnitude to the rally ceiling.
+    fn accelerate_ball(&mut self) {
+        let speed = (self.ball_velocity.length() * RALLY_SPEEDUP).min(self.max_speed);
+        self.ball_velocity = self.ball_velocity.normalize() * speed;
+    }
+
+    // Bounce the ball off a paddle by its impact angle: the further the hit is
+    // from the paddle centre, the steeper the deflection (up to MAX_BOUNCE_ANGLE).
+    // The ball's speed magnitude is preserved; only the direction changes.
+    fn deflect_off_paddle(&mut self, paddle_y: f32, is_right: bool) {
+        let rel = ((self.ball_position.y - (paddle_y + PADDLE_HEIGHT / 2.0)) / (PADDLE_HEIGHT / 2.0)).clamp(-1.0, 1.0);
+        let angle = rel * MAX_BOUNCE_ANGLE;
+        let speed = self.ball_velocity.length();
+        let dir = if is_right { -1.0 } else { 1.0 };
+        self.ball_velocity = Vec2::new(dir * speed * angle.cos(), speed * angle.sin());
+    }
+
+    // Drive the right paddle automatically in single-player mode. Each
+    // difficulty tracks the ball with a different reaction speed, deadzone and
+    // accuracy; Hard predicts where the ball will arrive rather than chasing it.
+    fn update_ai(&mut self, dt: f32) {
+        let paddle_centre = self.right_paddle_y + PADDLE_HEIGHT / 2.0;
+        let (speed, deadzone, target) = match self.difficulty {
+            Difficulty::Easy => (PADDLE_SPEED * 0.55, 45.0, self.ball_position.y + PADDLE_HEIGHT / 3.0),
+            Difficulty::Medium => (PADDLE_SPEED * 0.85, 15.0, self.ball_position.y),
+            Difficulty::Hard => (PADDLE_SPEED * 1.1, 4.0, self.predict_ball_y()),
+        };
+        let diff = target - paddle_centre;
+        if diff.abs() > deadzone {
+            let step = (speed * dt).min(diff.abs());
+            self.right_paddle_y += step * diff.signum();
+        }
+    }
+
+    // Extrapolate the ball's trajectory to the right paddle plane, reflecting
+    // off the top and bottom walls, so the Hard AI can aim ahead of the ball.
+    fn predict_ball_y(&self) -> f32 {
+        if self.ball_velocity.x <= 0.0 {
+            return WINDOW_HEIGHT / 2.0;
+        }
+        let target_x = WINDOW_WIDTH - PADDLE_WIDTH - BALL_RADIUS;
+        let dx = target_x - self.ball_position.x;
+        if dx <= 0.0 {
+            return self.ball_position.y;
+        }
+        let t = dx / self.ball_velocity.x;
+        let raw = self.ball_position.y + self.ball_velocity.y * t;
+        // Fold the unbounded position back into [0, WINDOW_HEIGHT] as a triangle wave.
+        let period = 2.0 * WINDOW_HEIGHT;
+        let folded = raw.rem_euclid(period);
+        if folded > WINDOW_HEIGHT {
+            period - folded
+        } else {
+            folded
+        }
+    }
+
     fn restart_game(&mut self) {
         self.left_paddle_y = WINDOW_HEIGHT / 2.0 - PADDLE_HEIGHT / 2.0;
         self.right_paddle_y = WINDOW_HEIGHT / 2.0 - PADDLE_HEIGHT / 2.0;
@@ -77,24 +198,73 @@ impl MainState {
         self.game_state = GameState::Menu;
     }
 
-    fn update(&mut self, _ctx: &mut Context) -> GameResult<()> {
+    fn update(&mut self, ctx: &mut Context) -> GameResult<()> {
         if let GameState::Playing = self.game_state {
+            let dt = ctx.time.delta().as_secs_f32();
+
+            // Apply continuous paddle movement from held keys.
+            if self.left_up_held && self.left_paddle_y > 0.0 {
+                self.left_paddle_y -= PADDLE_SPEED * dt;
+            }
+            if self.left_down_held && self.left_paddle_y + PADDLE_HEIGHT < WINDOW_HEIGHT {
+                self.left_paddle_y += PADDLE_SPEED * dt;
+            }
+            if !self.single_player {
+                if self.right_up_held && self.right_paddle_y > 0.0 {
+                    self.right_paddle_y -= PADDLE_SPEED * dt;
+                }
+                if self.right_down_held && self.right_paddle_y + PADDLE_HEIGHT < WINDOW_HEIGHT {
+                    self.right_paddle_y += PADDLE_SPEED * dt;
+                }
+            } else {
+                self.update_ai(dt);
+            }
+            self.left_paddle_y = self.left_paddle_y.clamp(0.0, WINDOW_HEIGHT - PADDLE_HEIGHT);
+            self.right_paddle_y = self.right_paddle_y.clamp(0.0, WINDOW_HEIGHT - PADDLE_HEIGHT);
+
             // Update ball position
-            self.ball_position += self.ball_velocity;
+            self.ball_position += self.ball_velocity * dt;
 
             // Check ball collision with top and bottom walls
             if self.ball_position.y - BALL_RADIUS < 0.0 || self.ball_position.y + BALL_RADIUS > WINDOW_HEIGHT {
                 self.ball_velocity.y = -self.ball_velocity.y;
+                self.wall_sound.play_detached(ctx)?;
             }
 
             // Check ball collision with paddles
             let left_paddle_rect = graphics::Rect::new(0.0, self.left_paddle_y, PADDLE_WIDTH, PADDLE_HEIGHT);
             let right_paddle_rect = graphics::Rect::new(WINDOW_WIDTH - PADDLE_WIDTH, self.right_paddle_y, PADDLE_WIDTH, PADDLE_HEIGHT);
             if self.ball_position.x - BALL_RADIUS < PADDLE_WIDTH && left_paddle_rect.contains(Point2 { x: self.ball_position.x, y: self.ball_position.y }) {
-                self.ball_velocity.x = -self.ball_velocity.x;
+                self.deflect_off_paddle(self.left_paddle_y, false);
+                self.accelerate_ball();
+                self.paddle_sound.play_detached(ctx)?;
             }
             if self.ball_position.x + BALL_RADIUS > WINDOW_WIDTH - PADDLE_WIDTH && right_paddle_rect.contains(Point2 { x: self.ball_position.x, y: self.ball_position.y }) {
-                self.ball_velocity.x = -self.ball_velocity.x;
+                self.deflect_off_paddle(self.right_paddle_y, true);
+                self.accelerate_ball();
+                self.paddle_sound.play_detached(ctx)?;
+            }
+
+            // Check for a miss: the ball slipping past either paddle scores a
+            // point for the opposing player and re-serves toward the conceder.
+            if self.ball_position.x < 0.0 {
+                self.score_sound.play_detached(ctx)?;
+                self.right_score += 1;
+                if self.right_score >= SCORE_TARGET {
+                    self.gameover_selection = 0;
+                    self.game_state = GameState::GameOver;
+                } else {
+                    self.serve_ball(true);
+                }
+            } else if self.ball_position.x > WINDOW_WIDTH {
+                self.score_sound.play_detached(ctx)?;
+                self.left_score += 1;
+                if self.left_score >= SCORE_TARGET {
+                    self.gameover_selection = 0;
+                    self.game_state = GameState::GameOver;
+                } else {
+                    self.serve_ball(false);
+                }
             }
         }
 
@@ -119,11 +289,15 @@ impl MainState {
                 canvas.draw(&title, DrawParam::default().dest(Vec2::new(250.0, 100.0)));
 
                 // Draw menu text
+                let mode_label = if self.single_player { "Mode: 1 Player".to_string() } else { "Mode: 2 Player".to_string() };
+                let volume_label = format!("Volume: {}%", (self.volume * 100.0).round() as i32);
                 let menu_texts = [
-                    ("Easy", self.menu_selection == 0),
-                    ("Medium", self.menu_selection == 1),
-                    ("Hard", self.menu_selection == 2),
-                    ("Back to Home", self.menu_selection == 3),
+                    (mode_label, self.menu_selection == 0),
+                    ("Easy".to_string(), self.menu_selection == 1),
+                    ("Medium".to_string(), self.menu_selection == 2),
+                    ("Hard".to_string(), self.menu_selection == 3),
+                    (volume_label, self.menu_selection == 4),
+                    ("Back to Home".to_string(), self.menu_selection == 5),
                 ];
                 let mut y = 200.0;
                 for (text, selected) in &menu_texts {
@@ -134,8 +308,8 @@ impl MainState {
                 }
 
                 // Draw instructions
-                let instructions = Text::new(TextFragment::new("Use W/S or Up/Down to navigate, Enter or Click to select").color(Color::WHITE).scale(20.0));
-                canvas.draw(&instructions, DrawParam::default().dest(Vec2::new(100.0, 400.0)));
+                let instructions = Text::new(TextFragment::new("Use W/S or Up/Down to navigate, Left/Right to adjust volume, Enter or Click to select").color(Color::WHITE).scale(20.0));
+                canvas.draw(&instructions, DrawParam::default().dest(Vec2::new(100.0, 520.0)));
             }
             GameState::Playing | GameState::Paused => {
                 // Draw paddles
@@ -149,16 +323,24 @@ impl MainState {
                 let ball_mesh = Mesh::new_circle(ctx, graphics::DrawMode::fill(), Point2 { x: self.ball_position.x, y: self.ball_position.y }, BALL_RADIUS, 2.0, Color::GREEN)?;
                 canvas.draw(&ball_mesh, DrawParam::default());
 
+                // Draw the scores at the top of the play field
+                let left_score = Text::new(TextFragment::new(self.left_score.to_string()).color(Color::WHITE).scale(40.0));
+                canvas.draw(&left_score, DrawParam::default().dest(Vec2::new(WINDOW_WIDTH / 2.0 - 80.0, 20.0)));
+                let right_score = Text::new(TextFragment::new(self.right_score.to_string()).color(Color::WHITE).scale(40.0));
+                canvas.draw(&right_score, DrawParam::default().dest(Vec2::new(WINDOW_WIDTH / 2.0 + 60.0, 20.0)));
+
                 // Draw help text
                 let help_text = Text::new(TextFragment::new("Controls:\nLeft Paddle: W/S or drag with mouse\nRight Paddle: Up/Down Arrows or drag with mouse\nPress P to Pause").color(Color::WHITE).scale(20.0));
                 canvas.draw(&help_text, DrawParam::default().dest(Vec2::new(10.0, 10.0)));
 
                 if self.game_state == GameState::Paused {
+                    let pause_volume_label = format!("Volume: {}%", (self.volume * 100.0).round() as i32);
                     let pause_menu_texts = [
-                        ("Resume", self.pause_selection == 0),
-                        ("Restart", self.pause_selection == 1),
-                        ("Stop Game", self.pause_selection == 2),
-                        ("Back to Home", self.pause_selection == 3),
+                        ("Resume".to_string(), self.pause_selection == 0),
+                        ("Restart".to_string(), self.pause_selection == 1),
+                        ("Stop Game".to_string(), self.pause_selection == 2),
+                        (pause_volume_label, self.pause_selection == 3),
+                        ("Back to Home".to_string(), self.pause_selection == 4),
                     ];
                     let mut y = 200.0;
                     for (text, selected) in &pause_menu_texts {
@@ -169,10 +351,34 @@ impl MainState {
                     }
 
                     // Draw instructions
-                    let instructions = Text::new(TextFragment::new("Use W/S or Up/Down to navigate, Enter or Click to select").color(Color::WHITE).scale(20.0));
-                    canvas.draw(&instructions, DrawParam::default().dest(Vec2::new(100.0, 400.0)));
+                    let instructions = Text::new(TextFragment::new("Use W/S or Up/Down to navigate, Left/Right to adjust volume, Enter or Click to select").color(Color::WHITE).scale(20.0));
+                    canvas.draw(&instructions, DrawParam::default().dest(Vec2::new(100.0, 470.0)));
                 }
             }
+            GameState::GameOver => {
+                // Announce the winner and mirror the pause menu navigation.
+                let winner = if self.left_score > self.right_score { "Left Player Wins!" } else { "Right Player Wins!" };
+                let title = Text::new(TextFragment::new(winner).color(Color::GREEN).scale(50.0));
+                canvas.draw(&title, DrawParam::default().dest(Vec2::new(150.0, 100.0)));
+
+                let score_line = Text::new(TextFragment::new(format!("{} - {}", self.left_score, self.right_score)).color(Color::WHITE).scale(40.0));
+                canvas.draw(&score_line, DrawParam::default().dest(Vec2::new(340.0, 160.0)));
+
+                let gameover_texts = [
+                    ("Restart", self.gameover_selection == 0),
+                    ("Back to Home", self.gameover_selection == 1),
+                ];
+                let mut y = 250.0;
+                for (text, selected) in &gameover_texts {
+                    let color = if *selected { Color::YELLOW } else { Color::WHITE };
+                    let menu_text = Text::new(TextFragment::new(text.to_string()).color(color).scale(30.0));
+                    canvas.draw(&menu_text, DrawParam::default().dest(Vec2::new(300.0, y)));
+                    y += 50.0;
+                }
+
+                let instructions = Text::new(TextFragment::new("Use W/S or Up/Down to navigate, Enter or Click to select").color(Color::WHITE).scale(20.0));
+                canvas.draw(&instructions, DrawParam::default().dest(Vec2::new(100.0, 400.0)));
+            }
         }
 
         canvas.finish(ctx)?;
@@ -194,25 +400,32 @@ impl MainState {
                         }
                     }
                     KeyCode::S | KeyCode::Down => {
-                        if self.menu_selection < 3 {
+                        if self.menu_selection < 5 {
                             self.menu_selection += 1;
                         }
                     }
+                    KeyCode::Left if self.menu_selection == 4 => {
+                        self.set_volume(self.volume - 0.1);
+                    }
+                    KeyCode::Right if self.menu_selection == 4 => {
+                        self.set_volume(self.volume + 0.1);
+                    }
                     KeyCode::Return => {
                         match self.menu_selection {
-                            0 => {
+                            0 => self.single_player = !self.single_player,
+                            1 => {
                                 self.difficulty = Difficulty::Easy;
                                 self.start_game();
                             }
-                            1 => {
+                            2 => {
                                 self.difficulty = Difficulty::Medium;
                                 self.start_game();
                             }
-                            2 => {
+                            3 => {
                                 self.difficulty = Difficulty::Hard;
                                 self.start_game();
                             }
-                            3 => self.game_state = GameState::Home,
+                            5 => self.game_state = GameState::Home,
                             _ => (),
                         }
                     }
@@ -221,26 +434,10 @@ impl MainState {
             }
             GameState::Playing => {
                 match keycode {
-                    KeyCode::W => {
-                        if self.left_paddle_y > 0.0 {
-                            self.left_paddle_y -= PADDLE_SPEED;
-                        }
-                    }
-                    KeyCode::S => {
-                        if self.left_paddle_y + PADDLE_HEIGHT < WINDOW_HEIGHT {
-                            self.left_paddle_y += PADDLE_SPEED;
-                        }
-                    }
-                    KeyCode::Up => {
-                        if self.right_paddle_y > 0.0 {
-                            self.right_paddle_y -= PADDLE_SPEED;
-                        }
-                    }
-                    KeyCode::Down => {
-                        if self.right_paddle_y + PADDLE_HEIGHT < WINDOW_HEIGHT {
-                            self.right_paddle_y += PADDLE_SPEED;
-                        }
-                    }
+                    KeyCode::W => self.left_up_held = true,
+                    KeyCode::S => self.left_down_held = true,
+                    KeyCode::Up if !self.single_player => self.right_up_held = true,
+                    KeyCode::Down if !self.single_player => self.right_down_held = true,
                     KeyCode::P => {
                         self.game_state = GameState::Paused;
                     }
@@ -255,16 +452,44 @@ impl MainState {
                         }
                     }
                     KeyCode::S | KeyCode::Down => {
-                        if self.pause_selection < 3 {
+                        if self.pause_selection < 4 {
                             self.pause_selection += 1;
                         }
                     }
+                    KeyCode::Left if self.pause_selection == 3 => {
+                        self.set_volume(self.volume - 0.1);
+                    }
+                    KeyCode::Right if self.pause_selection == 3 => {
+                        self.set_volume(self.volume + 0.1);
+                    }
                     KeyCode::Return => {
                         match self.pause_selection {
                             0 => self.game_state = GameState::Playing,
                             1 => self.restart_game(),
                             2 => self.stop_game(),
-                            3 => self.game_state = GameState::Home,
+                            4 => self.game_state = GameState::Home,
+                            _ => (),
+                        }
+                    }
+                    _ => (),
+                }
+            }
+            GameState::GameOver => {
+                match keycode {
+                    KeyCode::W | KeyCode::Up => {
+                        if self.gameover_selection > 0 {
+                            self.gameover_selection -= 1;
+                        }
+                    }
+                    KeyCode::S | KeyCode::Down => {
+                        if self.gameover_selection < 1 {
+                            self.gameover_selection += 1;
+                        }
+                    }
+                    KeyCode::Return => {
+                        match self.gameover_selection {
+                            0 => self.restart_game(),
+                            1 => self.game_state = GameState::Home,
                             _ => (),
                         }
                     }
@@ -274,6 +499,16 @@ impl MainState {
         }
     }
 
+    fn key_up_event(&mut self, _ctx: &mut Context, keycode: KeyCode) {
+        match keycode {
+            KeyCode::W => self.left_up_held = false,
+            KeyCode::S => self.left_down_held = false,
+            KeyCode::Up => self.right_up_held = false,
+            KeyCode::Down => self.right_down_held = false,
+            _ => (),
+        }
+    }
+
     fn mouse_button_down_event(&mut self, ctx: &mut Context, button: MouseButton, x: f32, y: f32) {
         if button == MouseButton::Left {
             match self.game_state {
@@ -286,6 +521,8 @@ impl MainState {
                         (300.0, 250.0),
                         (300.0, 300.0),
                         (300.0, 350.0),
+                        (300.0, 400.0),
+                        (300.0, 450.0),
                     ];
                     for (i, &(mx, my)) in menu_options.iter().enumerate() {
                         if x >= mx && x <= mx + 200.0 && y >= my && y <= my + 50.0 {
@@ -301,6 +538,7 @@ impl MainState {
                         (300.0, 250.0),
                         (300.0, 300.0),
                         (300.0, 350.0),
+                        (300.0, 400.0),
                     ];
                     for (i, &(px, py)) in pause_options.iter().enumerate() {
                         if x >= px && x <= px + 200.0 && y >= py && y <= py + 50.0 {
@@ -310,12 +548,25 @@ impl MainState {
                         }
                     }
                 }
+                GameState::GameOver => {
+                    let gameover_options = [
+                        (300.0, 250.0),
+                        (300.0, 300.0),
+                    ];
+                    for (i, &(gx, gy)) in gameover_options.iter().enumerate() {
+                        if x >= gx && x <= gx + 200.0 && y >= gy && y <= gy + 50.0 {
+                            self.gameover_selection = i;
+                            self.key_down_event(ctx, KeyCode::Return, false);
+                            break;
+                        }
+                    }
+                }
                 GameState::Playing => {
                     let left_paddle_rect = graphics::Rect::new(0.0, self.left_paddle_y, PADDLE_WIDTH, PADDLE_HEIGHT);
                     let right_paddle_rect = graphics::Rect::new(WINDOW_WIDTH - PADDLE_WIDTH, self.right_paddle_y, PADDLE_WIDTH, PADDLE_HEIGHT);
                     if left_paddle_rect.contains(Point2 { x, y }) {
                         self.left_paddle_dragging = true;
-                    } else if right_paddle_rect.contains(Point2 { x, y }) {
+                    } else if !self.single_player && right_paddle_rect.contains(Point2 { x, y }) {
                         self.right_paddle_dragging = true;
                     }
                 }
@@ -355,6 +606,13 @@ impl EventHandler for MainState {
         Ok(())
     }
 
+    fn key_up_event(&mut self, ctx: &mut Context, input: KeyInput) -> GameResult<()> {
+        if let Some(keycode) = input.keycode {
+            self.key_up_event(ctx, keycode);
+        }
+        Ok(())
+    }
+
     fn mouse_button_down_event(&mut self, ctx: &mut Context, button: MouseButton, x: f32, y: f32) -> GameResult<()> {
         self.mouse_button_down_event(ctx, button, x, y);
         Ok(())
@@ -372,10 +630,12 @@ impl EventHandler for MainState {
 }
 
 fn main() -> GameResult {
-    let (ctx, event_loop) = ggez::ContextBuilder::new("pong", "ggez")
+    let resource_dir = std::path::PathBuf::from("./resources");
+    let (mut ctx, event_loop) = ggez::ContextBuilder::new("pong", "ggez")
+        .add_resource_path(resource_dir)
         .window_setup(ggez::conf::WindowSetup::default().title("Pong"))
         .window_mode(ggez::conf::WindowMode::default().dimensions(WINDOW_WIDTH, WINDOW_HEIGHT))
         .build()?;
-    let state = MainState::new();
+    let state = MainState::new(&mut ctx)?;
     event::run(ctx, event_loop, state)
 }